@@ -0,0 +1,120 @@
+use proc_macro::TokenStream;
+use quote::format_ident;
+use razure::filesystem::SpecificationFile;
+use razure::generator::rust::{create_struct, flatten_all_of, render_required_helpers};
+use razure::parser::parse_specification_file;
+use razure::parser::resolver::{resolve_references, ReferenceIndex};
+use razure::parser::schema::DefinitionType;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Builds the `SpecificationFile` `typify!`'s spec path resolves to: the
+/// parent directory name becomes `domain_name`, matching the Azure REST API
+/// specs convention (a domain folder containing versioned `*.json` files)
+/// the rest of the pipeline assumes.
+fn specification_file(spec_path: &Path) -> SpecificationFile {
+    let domain_name = spec_path
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let file_name = spec_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("spec")
+        .to_string();
+
+    SpecificationFile {
+        domain_name,
+        file_name,
+        path: spec_path.to_path_buf(),
+    }
+}
+
+/// Parses the spec at `spec_path` through the same pipeline the offline
+/// generator uses (`parse_specification_file`, `ReferenceIndex`,
+/// `resolve_references`, `create_struct`/`flatten_all_of`) and expands into a
+/// `pub mod <spec file stem> { ... }` of struct definitions. Routing through
+/// this shared pipeline, rather than a second hand-rolled generator, is what
+/// keeps `typify!` and the offline CLI from silently drifting on `$ref`
+/// resolution, `allOf` flattening, enum support, and format handling: the
+/// generated Rust source text is identical either way, just reached through
+/// `syn::parse_file` here instead of written to disk.
+pub fn expand(spec_path: &Path) -> TokenStream {
+    let specification_file = specification_file(spec_path);
+
+    let Some(swagger) = parse_specification_file(&specification_file) else {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("could not parse spec file `{}`", spec_path.display()),
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let mut reference_index = ReferenceIndex::build(&swagger);
+    resolve_references(&specification_file, &swagger, &mut reference_index);
+
+    let module_name = format_ident!(
+        "{}",
+        spec_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("spec")
+            .replace(['-', '.'], "_")
+    );
+
+    let definitions = swagger.definitions.unwrap_or_default();
+    let mut names: Vec<&String> = definitions.keys().collect();
+    names.sort();
+
+    let mut emitted_enums = HashSet::new();
+    let mut required_helpers = HashSet::new();
+    let mut source = String::new();
+
+    for name in names {
+        let definition = &definitions[name];
+        let DefinitionType::Object { properties, .. } = &definition.schema else {
+            continue;
+        };
+
+        let flattened = flatten_all_of(definition, properties);
+        source.push_str(&create_struct(
+            name,
+            definition.description.as_deref(),
+            &flattened.properties,
+            &flattened.required,
+            &mut emitted_enums,
+            &mut required_helpers,
+        ));
+        source.push('\n');
+    }
+
+    // Expands into the caller's own crate, which has no dependency on
+    // `razure`, so the helpers the generated structs reference
+    // (`deserialize_null_as_default`, `base64_bytes`) must be spliced in as
+    // sibling items in this same module rather than referenced by path.
+    source.push_str(&render_required_helpers(&required_helpers));
+
+    let parsed_source = match syn::parse_file(&source) {
+        Ok(parsed_source) => parsed_source,
+        Err(error) => {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!("generated source for `{}` failed to parse: {error}", spec_path.display()),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let items = parsed_source.items;
+
+    let expanded = quote::quote! {
+        pub mod #module_name {
+            #(#items)*
+        }
+    };
+
+    expanded.into()
+}
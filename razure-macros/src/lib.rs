@@ -0,0 +1,41 @@
+//! Compile-time front end for Razure's spec-to-Rust generation.
+//!
+//! `razure::typify!("specs/compute.json")` parses the given spec and expands
+//! directly into a `pub mod` of request/response types in the caller's
+//! crate, at compile time, so consumers get statically typed Azure types
+//! without running the offline generator as a separate build step.
+//!
+//! Consumers must depend on `serde` and `serde_json` themselves; this macro
+//! expands to code that references those crates by name but does not
+//! re-export them.
+
+mod codegen;
+
+use proc_macro::TokenStream;
+use std::env;
+use std::path::PathBuf;
+use syn::LitStr;
+
+#[proc_macro]
+pub fn typify(input: TokenStream) -> TokenStream {
+    let spec_path = match syn::parse::<LitStr>(input) {
+        Ok(literal) => PathBuf::from(literal.value()),
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let manifest_dir = PathBuf::from(
+        env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is only set when running under cargo"),
+    );
+    let resolved_path = manifest_dir.join(&spec_path);
+
+    if let Err(error) = std::fs::metadata(&resolved_path) {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("could not read spec file `{}`: {error}", resolved_path.display()),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    codegen::expand(&resolved_path)
+}
@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// A single spec document on disk, identified by the Azure REST API specs
+/// convention of a domain folder (e.g. `compute`) containing versioned
+/// `*.json` files.
+#[derive(Debug, Clone)]
+pub struct SpecificationFile {
+    pub domain_name: String,
+    pub file_name: String,
+    pub path: PathBuf,
+}
+
+impl SpecificationFile {
+    pub fn contents(&self) -> std::io::Result<String> {
+        fs::read_to_string(&self.path)
+    }
+
+    /// Resolves a path that appears on the left-hand side of a cross-file
+    /// `$ref`, e.g. `../common/types.json`, relative to this file.
+    pub fn sibling(&self, relative_path: &str) -> SpecificationFile {
+        let resolved_path = self
+            .path
+            .parent()
+            .map(|parent| parent.join(relative_path))
+            .unwrap_or_else(|| PathBuf::from(relative_path));
+
+        let file_name = resolved_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        SpecificationFile {
+            domain_name: self.domain_name.clone(),
+            file_name,
+            path: resolved_path,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/// Maps an OpenAPI `type`/`format` pair to a precise Rust type. Exposed as a
+/// trait so a generator can swap in a different registry (e.g. one that
+/// maps `date-time` onto `time::OffsetDateTime` instead of `chrono`)
+/// without touching the definition/parameter generation code.
+pub trait FormatRegistry {
+    fn resolve(&self, schema_type: &str, format: Option<&str>) -> String;
+}
+
+/// The registry `RustGenerator` uses by default. The `chrono`, `uuid`, and
+/// `base64` mappings are feature-gated: a consumer who disables the
+/// matching cargo feature isn't forced to pull in that dependency, and the
+/// affected fields fall back to their coarse `type`-only Rust type.
+pub struct DefaultFormatRegistry;
+
+impl FormatRegistry for DefaultFormatRegistry {
+    fn resolve(&self, schema_type: &str, format: Option<&str>) -> String {
+        match (schema_type, format) {
+            ("integer", Some("int64")) => "i64".to_string(),
+            ("integer", Some("int32")) => "i32".to_string(),
+            ("integer", _) => "i32".to_string(),
+
+            ("number", Some("double")) => "f64".to_string(),
+            ("number", Some("float")) => "f32".to_string(),
+            ("number", _) => "f32".to_string(),
+
+            #[cfg(feature = "chrono")]
+            ("string", Some("date-time")) => "chrono::DateTime<chrono::Utc>".to_string(),
+            #[cfg(not(feature = "chrono"))]
+            ("string", Some("date-time")) => "String".to_string(),
+
+            #[cfg(feature = "chrono")]
+            ("string", Some("date")) => "chrono::NaiveDate".to_string(),
+            #[cfg(not(feature = "chrono"))]
+            ("string", Some("date")) => "String".to_string(),
+
+            #[cfg(feature = "uuid")]
+            ("string", Some("uuid")) => "uuid::Uuid".to_string(),
+            #[cfg(not(feature = "uuid"))]
+            ("string", Some("uuid")) => "String".to_string(),
+
+            #[cfg(feature = "base64")]
+            ("string", Some("byte")) | ("string", Some("binary")) => "Vec<u8>".to_string(),
+            #[cfg(not(feature = "base64"))]
+            ("string", Some("byte")) | ("string", Some("binary")) => "String".to_string(),
+
+            ("string", Some("duration")) => "std::time::Duration".to_string(),
+            ("string", _) => "String".to_string(),
+
+            ("boolean", _) => "bool".to_string(),
+            _ => "String".to_string(),
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::filesystem::SpecificationFile;
 use crate::generator::rust::{
-    create_file, create_lib_file, create_project, create_struct, create_struct_simple_type,
-    format_as_file_name,
+    create_file, create_lib_file, create_operation_method, create_project, create_struct,
+    create_struct_simple_type, flatten_all_of, format_as_file_name, render_required_helpers,
 };
 use crate::generator::{ConversionType, Generator};
 use crate::parser::parse_specification_file;
-use crate::parser::schema::{Definition, DefinitionType, Parameter, PropertyType};
-use std::collections::{BTreeMap, HashMap};
+use crate::parser::resolver::{resolve_references, ReferenceIndex};
+use crate::parser::schema::{Definition, DefinitionType, Parameter, PathItem, PropertyType, Server};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[derive(Default)]
 pub struct RustGenerator {}
@@ -15,12 +16,27 @@ impl RustGenerator {
     fn generate_definitions(
         &self,
         definitions: &HashMap<String, Definition>,
+        required_helpers: &mut HashSet<&'static str>,
     ) -> HashMap<String, String> {
         let mut structs: HashMap<String, String> = HashMap::new();
+        // Shared across every definition in this file, not per-definition:
+        // an `x-ms-enum` name like `ProvisioningState` is reused across many
+        // definitions in the same spec file, and `create_struct` must only
+        // render it once.
+        let mut emitted_enums = HashSet::new();
+
         for (name, definition) in definitions {
             match &definition.schema {
-                DefinitionType::Object { properties } => {
-                    let struct_str = create_struct(name, properties);
+                DefinitionType::Object { properties, .. } => {
+                    let flattened = flatten_all_of(definition, properties);
+                    let struct_str = create_struct(
+                        name,
+                        definition.description.as_deref(),
+                        &flattened.properties,
+                        &flattened.required,
+                        &mut emitted_enums,
+                        required_helpers,
+                    );
                     structs.insert(name.to_string(), struct_str);
                 }
                 _ => {}
@@ -55,6 +71,24 @@ impl RustGenerator {
         }
         structs
     }
+
+    fn generate_operations(
+        &self,
+        paths: &HashMap<String, PathItem>,
+        servers: &[Server],
+    ) -> HashMap<String, String> {
+        let mut methods: HashMap<String, String> = HashMap::new();
+        let server = servers.first();
+
+        for (path, path_item) in paths {
+            for (method, operation) in &path_item.operations {
+                let method_str = create_operation_method(path, method, operation, server);
+                methods.insert(operation.id.clone(), method_str);
+            }
+        }
+
+        methods
+    }
 }
 
 impl Generator for RustGenerator {
@@ -68,18 +102,34 @@ impl Generator for RustGenerator {
                     let swagger = parse_specification_file(specification_file);
 
                     if let Some(swagger) = swagger {
+                        let mut reference_index = ReferenceIndex::build(&swagger);
+                        resolve_references(specification_file, &swagger, &mut reference_index);
+
                         let file_name = format_as_file_name(specification_file.file_name.as_str());
                         let domain_file_name =
                             format_as_file_name(specification_file.domain_name.as_str());
 
                         let mut data: HashMap<String, String> = HashMap::new();
+                        let mut required_helpers = HashSet::new();
 
                         if let Some(parameters) = &swagger.parameters {
                             data.extend(self.generate_parameters(parameters));
                         }
 
                         if let Some(definitions) = &swagger.definitions {
-                            data.extend(self.generate_definitions(definitions));
+                            data.extend(self.generate_definitions(definitions, &mut required_helpers));
+                        }
+
+                        if let Some(paths) = &swagger.paths {
+                            data.extend(self.generate_operations(paths, &swagger.servers));
+                        }
+
+                        let helpers_source = render_required_helpers(&required_helpers);
+                        if !helpers_source.is_empty() {
+                            // Top-level item order doesn't matter to rustc; the key
+                            // only needs to be unique among the struct/method names
+                            // the `generate_*` calls above insert into `data`.
+                            data.insert("_required_helpers".to_string(), helpers_source);
                         }
 
                         if data.is_empty() {
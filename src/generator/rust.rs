@@ -0,0 +1,693 @@
+use crate::generator::format_registry::{DefaultFormatRegistry, FormatRegistry};
+use crate::generator::string_formatter::{
+    format_name_as_valid_field_identifier, format_name_as_valid_struct_identifier,
+    is_valid_snake_case_identifier,
+};
+use crate::parser::models::{HttpStatus, Method};
+use crate::parser::schema::{
+    Definition, DefinitionProperty, DefinitionType, Operation, Parameter, ParameterOrReference,
+    PropertyType, ReferenceTarget, Server,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::rc::Rc;
+
+/// Header names the client sets itself; a spec declaring one of these as an
+/// explicit `in: header` parameter is ignored rather than generating a
+/// method that could fight the client over them.
+const RESERVED_HEADER_NAMES: [&str; 3] = ["content-type", "accept", "authorization"];
+
+/// Creates the output crate's directory skeleton (`<output_path>/src`).
+pub fn create_project(output_path: &str) -> io::Result<()> {
+    fs::create_dir_all(format!("{output_path}/src"))
+}
+
+/// Writes one generated file containing every struct/type in `data`.
+pub fn create_file(file_path: &str, data: &HashMap<String, String>) -> io::Result<()> {
+    let mut names: Vec<&String> = data.keys().collect();
+    names.sort();
+
+    let mut contents = String::new();
+    for name in names {
+        contents.push_str(&data[name]);
+        contents.push('\n');
+    }
+
+    fs::write(file_path, contents)
+}
+
+/// Writes `<output_path>/src/lib.rs`, declaring every generated module.
+pub fn create_lib_file(
+    output_path: &str,
+    file_mod_statements: &BTreeMap<String, String>,
+) -> io::Result<()> {
+    let mut contents = String::new();
+    for statement in file_mod_statements.values() {
+        contents.push_str(statement);
+    }
+
+    fs::write(format!("{output_path}/src/lib.rs"), contents)
+}
+
+/// Converts a spec file name (e.g. `compute.json`) into a valid Rust module
+/// name fragment (e.g. `compute`).
+pub fn format_as_file_name(name: &str) -> String {
+    name.trim_end_matches(".json")
+        .replace(['-', '.'], "_")
+        .to_lowercase()
+}
+
+pub fn create_struct_simple_type(name: &String, struct_type: String) -> String {
+    let formatted_name = format_name_as_valid_struct_identifier(name);
+    format!("pub struct {formatted_name}({struct_type});")
+}
+
+/// A definition's properties and `required` set after merging in every
+/// `allOf` base definition.
+pub struct FlattenedDefinition {
+    pub properties: HashMap<String, DefinitionProperty>,
+    pub required: Vec<String>,
+}
+
+/// Resolves every `allOf` entry on `definition` (already resolved by
+/// `resolve_references`) and merges each base definition's properties and
+/// `required` set into `own_properties`/`definition.required`, so a type
+/// extending e.g. `TrackedResource` generates as one flat struct rather than
+/// requiring the base to be generated and composed separately. A base
+/// definition is flattened recursively, since Azure resource hierarchies
+/// commonly chain `allOf` more than one level deep (e.g. `TrackedResource` ->
+/// `Resource`). A property declared on both a base and the derived
+/// definition keeps the most-derived copy. Shared between the offline
+/// generator and the `razure-macros` compile-time macro so both produce the
+/// same type for the same spec.
+pub fn flatten_all_of(
+    definition: &Definition,
+    own_properties: &HashMap<String, DefinitionProperty>,
+) -> FlattenedDefinition {
+    let Some(all_of) = &definition.all_of else {
+        return FlattenedDefinition {
+            properties: own_properties.clone(),
+            required: definition.required.clone().unwrap_or_default(),
+        };
+    };
+
+    let mut properties = HashMap::new();
+    let mut required = Vec::new();
+
+    for reference in all_of {
+        let Some(resolved) = reference.resolved.borrow().clone() else {
+            continue;
+        };
+
+        let ReferenceTarget::Definition(base) = &resolved.target else {
+            continue;
+        };
+
+        let base_own_properties = match &base.schema {
+            DefinitionType::Object { properties, .. } => properties.clone(),
+            _ => HashMap::new(),
+        };
+        let flattened_base = flatten_all_of(base, &base_own_properties);
+
+        for (name, property) in flattened_base.properties {
+            properties.insert(name, property);
+        }
+
+        for name in flattened_base.required {
+            if !required.contains(&name) {
+                required.push(name);
+            }
+        }
+    }
+
+    for (name, property) in own_properties {
+        properties.insert(name.clone(), property.clone());
+    }
+
+    for name in definition.required.iter().flatten() {
+        if !required.contains(name) {
+            required.push(name.clone());
+        }
+    }
+
+    FlattenedDefinition { properties, required }
+}
+
+/// Renders a generated struct for a single definition, mirroring the shape
+/// Azure's own generated SDKs use: a doc comment per field, `#[serde(rename =
+/// "...")]` for wire names that aren't valid snake_case identifiers, and
+/// `Option<T>` with `#[serde(default, skip_serializing_if = "Option::is_none")]`
+/// for any field not listed in `required`.
+///
+/// `emitted_enums` tracks which `x-ms-enum`/`enum` names have already been
+/// rendered for the output file this struct is being written into: Azure
+/// specs reuse the same `x-ms-enum` name (e.g. `ProvisioningState`) across
+/// many definitions, so without this a shared enum would be emitted once per
+/// definition that references it and fail to compile as a duplicate
+/// definition.
+pub fn create_struct(
+    name: &str,
+    description: Option<&str>,
+    properties: &HashMap<String, DefinitionProperty>,
+    required: &[String],
+    emitted_enums: &mut HashSet<String>,
+    required_helpers: &mut HashSet<&'static str>,
+) -> String {
+    let struct_name = format_name_as_valid_struct_identifier(name);
+    let mut preamble = String::new();
+    let mut body = String::new();
+
+    let mut wire_names: Vec<&String> = properties.keys().collect();
+    wire_names.sort();
+
+    for wire_name in wire_names {
+        let property = &properties[wire_name];
+
+        if let Some(enum_values) = &property.enum_values {
+            if emitted_enums.insert(enum_type_name(wire_name, property)) {
+                preamble.push_str(&create_enum_for_property(wire_name, property, enum_values));
+            }
+        }
+
+        body.push_str(&create_struct_field(
+            wire_name,
+            property,
+            required.iter().any(|name| name == wire_name),
+            required_helpers,
+        ));
+    }
+
+    let mut output = preamble;
+
+    if let Some(description) = description {
+        let _ = writeln!(output, "#[doc = {description:?}]");
+    }
+
+    let _ = writeln!(output, "#[derive(Debug, serde::Serialize, serde::Deserialize)]");
+    let _ = writeln!(output, "pub struct {struct_name} {{");
+    output.push_str(&body);
+    let _ = writeln!(output, "}}");
+    output
+}
+
+fn create_struct_field(
+    wire_name: &str,
+    property: &DefinitionProperty,
+    is_required: bool,
+    required_helpers: &mut HashSet<&'static str>,
+) -> String {
+    let mut output = String::new();
+    let field_name = format_name_as_valid_field_identifier(wire_name);
+    let read_only = property.read_only.unwrap_or(false);
+
+    match (&property.description, read_only) {
+        (Some(description), true) => {
+            let _ = writeln!(output, "    #[doc = {:?}]", format!("{description} (read-only)."));
+        }
+        (Some(description), false) => {
+            let _ = writeln!(output, "    #[doc = {description:?}]");
+        }
+        (None, true) => {
+            let _ = writeln!(output, "    #[doc = \"Read-only.\"]");
+        }
+        (None, false) => {}
+    }
+
+    if !is_valid_snake_case_identifier(wire_name) {
+        let _ = writeln!(output, "    #[serde(rename = {wire_name:?})]");
+    }
+
+    // `Option<Vec<T>>` already deserializes a JSON `null` as `None` on its
+    // own; the custom deserializer is only needed for a *required* array
+    // field, where `null` would otherwise fail to deserialize as `Vec<T>`.
+    let is_array = matches!(&property.schema, DefinitionType::Array { .. });
+    if is_array && is_required {
+        required_helpers.insert(DESERIALIZE_NULL_AS_DEFAULT);
+        let _ = writeln!(
+            output,
+            "    #[serde(default, deserialize_with = \"deserialize_null_as_default\")]"
+        );
+    }
+
+    if is_base64_bytes_property(property) {
+        required_helpers.insert(BASE64_BYTES);
+        let with_module = if is_required { "base64_bytes" } else { "base64_bytes::option" };
+        let _ = writeln!(output, "    #[serde(with = {with_module:?})]");
+    }
+
+    let rust_type = field_type_name(wire_name, property);
+
+    let field_type = if is_required {
+        rust_type
+    } else {
+        let _ = writeln!(
+            output,
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]"
+        );
+        format!("Option<{rust_type}>")
+    };
+
+    let _ = writeln!(output, "    pub {field_name}: {field_type},");
+    output
+}
+
+/// A property with an `enum`/`x-ms-enum` is typed as the generated enum
+/// rather than `String`; everything else routes through the format
+/// registry, which picks a precise Rust type from the property's `type` and
+/// OpenAPI `format` hint (falling back to the coarse per-`type` default).
+fn field_type_name(wire_name: &str, property: &DefinitionProperty) -> String {
+    if property.enum_values.is_some() {
+        enum_type_name(wire_name, property)
+    } else {
+        resolve_property_type(property)
+    }
+}
+
+/// A property typed as `{"$ref": "#/definitions/Foo"}` deserializes `schema`
+/// to an empty `DefinitionType::Object` (Azure's $ref objects carry no
+/// sibling keys), so the reference must be checked before falling back to
+/// `schema`-based inference, which would otherwise emit `serde_json::Value`
+/// for the overwhelmingly common "reference to another definition" case. A
+/// pointer discovered to be recursive (`A -> B -> A`) by `resolve_references`
+/// is boxed so the generated type stays finite-sized.
+fn resolve_property_type(property: &DefinitionProperty) -> String {
+    if let Some(resolved) = property.resolved_reference.borrow().as_ref() {
+        if matches!(&resolved.target, ReferenceTarget::Definition(_)) {
+            let type_name = property
+                .reference
+                .as_deref()
+                .and_then(|path| path.rsplit('/').next())
+                .unwrap_or("Value");
+            let type_name = format_name_as_valid_struct_identifier(type_name);
+
+            return if resolved.recursive {
+                format!("Box<{type_name}>")
+            } else {
+                type_name
+            };
+        }
+    }
+
+    let registry = DefaultFormatRegistry;
+    let format = property.format.as_deref();
+
+    match &property.schema {
+        DefinitionType::Object { .. } => "serde_json::Value".to_string(),
+        DefinitionType::Array { items, .. } => format!("Vec<{}>", resolve_property_type(items)),
+        DefinitionType::String { .. } => registry.resolve("string", format),
+        DefinitionType::Number { .. } => registry.resolve("number", format),
+        DefinitionType::Integer { .. } => registry.resolve("integer", format),
+        DefinitionType::Boolean { .. } => registry.resolve("boolean", format),
+    }
+}
+
+#[cfg(feature = "base64")]
+fn is_base64_bytes_property(property: &DefinitionProperty) -> bool {
+    matches!(&property.schema, DefinitionType::String { .. })
+        && matches!(property.format.as_deref(), Some("byte") | Some("binary"))
+}
+
+#[cfg(not(feature = "base64"))]
+fn is_base64_bytes_property(_property: &DefinitionProperty) -> bool {
+    false
+}
+
+/// Key `required_helpers` is tracked under for the `deserialize_null_as_default`
+/// helper.
+const DESERIALIZE_NULL_AS_DEFAULT: &str = "deserialize_null_as_default";
+/// Key `required_helpers` is tracked under for the `base64_bytes` helper
+/// module.
+const BASE64_BYTES: &str = "base64_bytes";
+
+/// Azure frequently returns `null` instead of `[]` for empty collections;
+/// treat a JSON `null` the same as an absent/empty array instead of failing
+/// deserialization.
+///
+/// Generated output doesn't depend on the `razure` crate (the offline
+/// generator's output crate has no such dependency, and `typify!` expands
+/// into the caller's own crate root), so this can't be referenced via a
+/// `crate::...` path from generated code. Instead its source is inlined
+/// verbatim as a sibling item into any generated file/module that needs it;
+/// see `render_required_helpers`.
+const DESERIALIZE_NULL_AS_DEFAULT_SOURCE: &str = r#"
+fn deserialize_null_as_default<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+"#;
+
+/// `serde(with = "...")` target for `Vec<u8>` fields backed by a `byte`/
+/// `binary` formatted string on the wire: standard base64 over JSON strings.
+/// Inlined into generated output for the same reason as
+/// `DESERIALIZE_NULL_AS_DEFAULT_SOURCE` above.
+#[cfg(feature = "base64")]
+const BASE64_BYTES_SOURCE: &str = r#"
+mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded.as_bytes()).map_err(serde::de::Error::custom)
+    }
+
+    /// `serde(with = "...")` target for an optional `byte`/`binary` formatted
+    /// string field, i.e. `Option<Vec<u8>>` rather than `Vec<u8>`.
+    pub mod option {
+        use base64::engine::general_purpose::STANDARD;
+        use base64::Engine as _;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S>(bytes: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match bytes {
+                Some(bytes) => serializer.serialize_str(&STANDARD.encode(bytes)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let Some(encoded) = Option::<String>::deserialize(deserializer)? else {
+                return Ok(None);
+            };
+            STANDARD
+                .decode(encoded.as_bytes())
+                .map(Some)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+"#;
+
+/// Renders the source for every helper named in `required_helpers` (as
+/// recorded by `create_struct_field`), so the generated file/module that
+/// needs `deserialize_null_as_default` and/or `base64_bytes` carries its own
+/// copy rather than reaching back into `razure` via a `crate::...` path that
+/// doesn't resolve from generated code.
+pub fn render_required_helpers(required_helpers: &HashSet<&'static str>) -> String {
+    let mut output = String::new();
+
+    if required_helpers.contains(DESERIALIZE_NULL_AS_DEFAULT) {
+        output.push_str(DESERIALIZE_NULL_AS_DEFAULT_SOURCE);
+    }
+
+    #[cfg(feature = "base64")]
+    if required_helpers.contains(BASE64_BYTES) {
+        output.push_str(BASE64_BYTES_SOURCE);
+    }
+
+    output
+}
+
+fn enum_type_name(wire_name: &str, property: &DefinitionProperty) -> String {
+    let name = property
+        .x_ms_enum
+        .as_ref()
+        .map(|x_ms_enum| x_ms_enum.name.as_str())
+        .unwrap_or(wire_name);
+    format_name_as_valid_struct_identifier(name)
+}
+
+/// Renders an `enum`/`x-ms-enum` property as a standalone Rust enum with a
+/// manual `Serialize`/`Deserialize` implementation mapping each wire string
+/// to its PascalCase variant. When `x-ms-enum.modelAsString` is set, an
+/// `Unknown(String)` catch-all variant is added so values the client doesn't
+/// recognize yet still round-trip instead of failing deserialization.
+fn create_enum_for_property(wire_name: &str, property: &DefinitionProperty, values: &[String]) -> String {
+    let enum_name = enum_type_name(wire_name, property);
+    let model_as_string = property
+        .x_ms_enum
+        .as_ref()
+        .and_then(|x_ms_enum| x_ms_enum.model_as_string)
+        .unwrap_or(false);
+
+    let variant_name = |value: &str| format_name_as_valid_struct_identifier(value);
+    let mut output = String::new();
+
+    let _ = writeln!(output, "#[derive(Debug, Clone, PartialEq, Eq)]");
+    let _ = writeln!(output, "pub enum {enum_name} {{");
+    for value in values {
+        let _ = writeln!(output, "    {},", variant_name(value));
+    }
+    if model_as_string {
+        let _ = writeln!(output, "    Unknown(String),");
+    }
+    let _ = writeln!(output, "}}");
+    let _ = writeln!(output);
+
+    let _ = writeln!(output, "impl serde::Serialize for {enum_name} {{");
+    let _ = writeln!(
+        output,
+        "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {{"
+    );
+    let _ = writeln!(output, "        let wire_value = match self {{");
+    for value in values {
+        let _ = writeln!(output, "            {enum_name}::{} => \"{value}\",", variant_name(value));
+    }
+    if model_as_string {
+        let _ = writeln!(output, "            {enum_name}::Unknown(value) => value.as_str(),");
+    }
+    let _ = writeln!(output, "        }};");
+    let _ = writeln!(output, "        serializer.serialize_str(wire_value)");
+    let _ = writeln!(output, "    }}");
+    let _ = writeln!(output, "}}");
+    let _ = writeln!(output);
+
+    let _ = writeln!(output, "impl<'de> serde::Deserialize<'de> for {enum_name} {{");
+    let _ = writeln!(
+        output,
+        "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {{"
+    );
+    let _ = writeln!(output, "        let wire_value = String::deserialize(deserializer)?;");
+    let _ = writeln!(output, "        Ok(match wire_value.as_str() {{");
+    for value in values {
+        let _ = writeln!(output, "            \"{value}\" => {enum_name}::{},", variant_name(value));
+    }
+    if model_as_string {
+        let _ = writeln!(output, "            _ => {enum_name}::Unknown(wire_value),");
+    } else {
+        let known_values = values.iter().map(|value| format!("{value:?}")).collect::<Vec<_>>().join(", ");
+        let _ = writeln!(
+            output,
+            "            _ => return Err(serde::de::Error::unknown_variant(&wire_value, &[{known_values}])),"
+        );
+    }
+    let _ = writeln!(output, "        }})");
+    let _ = writeln!(output, "    }}");
+    let _ = writeln!(output, "}}");
+    let _ = writeln!(output);
+
+    output
+}
+
+/// Renders one async client method for a single `path`/`method`/`operation`
+/// triple: path parameters become format-string substitutions into the URL
+/// template, query parameters become query-string pairs, header parameters
+/// become request headers, and an `in: body` parameter becomes a typed
+/// request body argument. The return type is derived from whichever of
+/// `200`/`201`/`202`/`204`/`default` the operation declares.
+pub fn create_operation_method(
+    path: &str,
+    method: &Method,
+    operation: &Operation,
+    server: Option<&Server>,
+) -> String {
+    let method_name = format_name_as_valid_field_identifier(&operation.id);
+    let base_url = server.map(|server| server.url.as_str()).unwrap_or_default();
+
+    // A parameter declared as a bare `$ref` (the norm for shared parameters
+    // like `api-version`/`subscriptionId`) resolves to a `Parameter` via the
+    // same `ReferenceIndex` that resolves definition `$ref`s; an inline
+    // parameter is used as-is. A pointer `resolve_references` couldn't
+    // resolve is dropped with a warning rather than silently, so a method
+    // missing e.g. `api-version` is at least visible in build output.
+    let mut resolved_parameters: Vec<Rc<Parameter>> = Vec::new();
+    for parameter in &operation.parameters {
+        match parameter {
+            ParameterOrReference::Parameter(parameter) => {
+                resolved_parameters.push(Rc::new(parameter.clone()));
+            }
+            ParameterOrReference::Reference(reference) => match reference.resolved.borrow().as_ref() {
+                Some(resolved) => {
+                    if let ReferenceTarget::Parameter(parameter) = &resolved.target {
+                        resolved_parameters.push(Rc::clone(parameter));
+                    }
+                }
+                None => eprintln!(
+                    "warning: could not resolve parameter reference {} for operation {}",
+                    reference.path, operation.id
+                ),
+            },
+        }
+    }
+
+    let mut path_params = Vec::new();
+    let mut query_params = Vec::new();
+    let mut header_params = Vec::new();
+    let mut body_param = None;
+
+    for parameter in &resolved_parameters {
+        match parameter.location.as_deref() {
+            Some("path") => path_params.push(parameter.as_ref()),
+            Some("query") => query_params.push(parameter.as_ref()),
+            Some("header") => {
+                let is_reserved = parameter
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| RESERVED_HEADER_NAMES.contains(&name.to_lowercase().as_str()));
+                if !is_reserved {
+                    header_params.push(parameter.as_ref());
+                }
+            }
+            Some("body") => body_param = Some(parameter.as_ref()),
+            _ => {}
+        }
+    }
+
+    let mut signature_params = Vec::new();
+    for parameter in path_params.iter().chain(query_params.iter()).chain(header_params.iter()) {
+        if let Some(name) = &parameter.name {
+            signature_params.push(format!(
+                "{}: {}",
+                format_name_as_valid_field_identifier(name),
+                parameter_rust_type(parameter)
+            ));
+        }
+    }
+
+    // OpenAPI 3.0 carries the request payload as `requestBody` rather than a
+    // Swagger 2.0 `in: body` parameter; either one means the generated
+    // method takes a body argument.
+    let has_body = body_param.is_some() || operation.request_body.is_some();
+    if has_body {
+        signature_params.push("body: &impl serde::Serialize".to_string());
+    }
+
+    let url_template = path_params.iter().fold(path.to_string(), |template, parameter| match &parameter.name {
+        Some(name) => template.replace(
+            &format!("{{{name}}}"),
+            &format!("{{{}}}", format_name_as_valid_field_identifier(name)),
+        ),
+        None => template,
+    });
+
+    let return_type = operation_return_type(operation);
+    let mut output = String::new();
+
+    if let Some(description) = &operation.description {
+        let _ = writeln!(output, "#[doc = {description:?}]");
+    }
+
+    let _ = writeln!(output, "impl Client {{");
+    let _ = writeln!(
+        output,
+        "    pub async fn {method_name}(&self, {}) -> Result<{return_type}, reqwest::Error> {{",
+        signature_params.join(", "),
+    );
+    let _ = writeln!(output, "        let url = format!(\"{base_url}{url_template}\");");
+    let _ = writeln!(
+        output,
+        "        let mut request = self.http_client.{}(url);",
+        method_as_reqwest_method(method)
+    );
+
+    if !query_params.is_empty() {
+        let query_pairs = query_params
+            .iter()
+            .filter_map(|parameter| parameter.name.as_ref())
+            .map(|name| format!("(\"{name}\", {}.to_string())", format_name_as_valid_field_identifier(name)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(output, "        request = request.query(&[{query_pairs}]);");
+    }
+
+    for parameter in &header_params {
+        if let Some(name) = &parameter.name {
+            let _ = writeln!(
+                output,
+                "        request = request.header(\"{name}\", {});",
+                format_name_as_valid_field_identifier(name)
+            );
+        }
+    }
+
+    if has_body {
+        let _ = writeln!(output, "        request = request.json(body);");
+    }
+
+    let _ = writeln!(output, "        request.send().await?.json().await");
+    let _ = writeln!(output, "    }}");
+    let _ = writeln!(output, "}}");
+
+    output
+}
+
+fn method_as_reqwest_method(method: &Method) -> &'static str {
+    match method {
+        Method::Get => "get",
+        Method::Post => "post",
+        Method::Put => "put",
+        Method::Delete => "delete",
+        Method::Patch => "patch",
+        Method::Head => "head",
+    }
+}
+
+/// Routes a path/query/header parameter's `type`+`format` through the same
+/// format registry struct fields use, except for strings: a function
+/// argument borrows (`&str`) rather than owning, since callers pass these
+/// by value at each call site rather than storing them.
+fn parameter_rust_type(parameter: &Parameter) -> String {
+    let registry = DefaultFormatRegistry;
+    let format = parameter.format.as_deref();
+
+    match parameter.property_type {
+        Some(PropertyType::Integer) => registry.resolve("integer", format),
+        Some(PropertyType::Number) => registry.resolve("number", format),
+        Some(PropertyType::Boolean) => registry.resolve("boolean", format),
+        _ => "&str".to_string(),
+    }
+}
+
+/// Picks the response describing a successful call (preferring `200`/`201`/
+/// `202`/`204` over `default`) and renders its schema's `$ref` target as a
+/// Rust type name, or `()` when the operation declares no response schema.
+fn operation_return_type(operation: &Operation) -> String {
+    [
+        HttpStatus::Ok,
+        HttpStatus::Created,
+        HttpStatus::Accepted,
+        HttpStatus::NoContent,
+    ]
+    .iter()
+    .find_map(|status| operation.responses.get(status))
+    .or_else(|| operation.responses.get(&HttpStatus::Default))
+    .and_then(|response| response.schema.as_ref())
+    .map(|reference| {
+        let type_name = reference.path.rsplit('/').next().unwrap_or(&reference.path);
+        format_name_as_valid_struct_identifier(type_name)
+    })
+    .unwrap_or_else(|| "()".to_string())
+}
+
@@ -0,0 +1,73 @@
+/// Converts an arbitrary spec identifier (wire name, definition name, etc.)
+/// into a valid Rust struct/enum identifier: PascalCase, with anything that
+/// isn't alphanumeric dropped and used instead as a word boundary.
+pub fn format_name_as_valid_struct_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    let mut capitalize_next = true;
+
+    for ch in name.chars() {
+        if ch.is_alphanumeric() {
+            if capitalize_next {
+                identifier.extend(ch.to_uppercase());
+                capitalize_next = false;
+            } else {
+                identifier.push(ch);
+            }
+        } else {
+            capitalize_next = true;
+        }
+    }
+
+    if identifier.chars().next().is_some_and(|ch| ch.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+
+    identifier
+}
+
+const RUST_KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do",
+    "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self",
+    "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield", "gen",
+];
+
+/// Converts an arbitrary wire name into a valid Rust field identifier:
+/// snake_case, with anything that isn't alphanumeric dropped and used
+/// instead as a word boundary. A result that is a Rust keyword (e.g. `type`)
+/// gets a trailing underscore (`type_`).
+pub fn format_name_as_valid_field_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    let mut previous_was_lower_or_digit = false;
+
+    for ch in name.chars() {
+        if ch.is_alphanumeric() {
+            if ch.is_uppercase() && previous_was_lower_or_digit {
+                identifier.push('_');
+            }
+            identifier.extend(ch.to_lowercase());
+            previous_was_lower_or_digit = ch.is_lowercase() || ch.is_numeric();
+        } else if !identifier.is_empty() {
+            identifier.push('_');
+            previous_was_lower_or_digit = false;
+        }
+    }
+
+    let identifier = identifier.trim_matches('_').to_string();
+
+    if identifier.chars().next().is_some_and(|ch| ch.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else if RUST_KEYWORDS.contains(&identifier.as_str()) {
+        format!("{identifier}_")
+    } else {
+        identifier
+    }
+}
+
+/// True when `name` is already a valid snake_case Rust identifier, and a
+/// generated field for it therefore doesn't need a `#[serde(rename = "...")]`
+/// attribute to round-trip the original wire name.
+pub fn is_valid_snake_case_identifier(name: &str) -> bool {
+    !name.is_empty() && format_name_as_valid_field_identifier(name) == name
+}
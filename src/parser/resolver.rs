@@ -0,0 +1,294 @@
+use crate::filesystem::SpecificationFile;
+use crate::parser::schema::reference::{ReferenceTarget, ResolvedReference};
+use crate::parser::schema::{
+    Definition, DefinitionProperty, DefinitionType, Operation, Parameter, ParameterOrReference, Swagger,
+};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Index of every `$ref` target reachable from a specification file, keyed
+/// by the Swagger 2.0 form of the pointer (`#/definitions/Foo`,
+/// `../common/types.json#/definitions/Resource`); `resolve_pointer` maps an
+/// OpenAPI 3.0 `#/components/schemas/...`/`#/components/parameters/...`
+/// pointer onto that form before looking it up. Cross-file targets are
+/// indexed lazily, the first time a pointer into that file is resolved.
+#[derive(Default)]
+pub struct ReferenceIndex {
+    definitions: HashMap<String, Rc<Definition>>,
+    parameters: HashMap<String, Rc<Parameter>>,
+    loaded_files: HashMap<String, ()>,
+}
+
+impl ReferenceIndex {
+    /// Indexes every definition and parameter directly reachable from
+    /// `swagger`, addressable by their local (same-file) pointer.
+    pub fn build(swagger: &Swagger) -> ReferenceIndex {
+        let mut index = ReferenceIndex::default();
+        index.index_local(swagger, "");
+        index
+    }
+
+    fn index_local(&mut self, swagger: &Swagger, file_prefix: &str) {
+        if let Some(definitions) = &swagger.definitions {
+            for (name, definition) in definitions {
+                self.definitions.insert(
+                    format!("{file_prefix}#/definitions/{name}"),
+                    Rc::new(definition.clone()),
+                );
+            }
+        }
+
+        if let Some(parameters) = &swagger.parameters {
+            for (name, parameter) in parameters {
+                self.parameters.insert(
+                    format!("{file_prefix}#/parameters/{name}"),
+                    Rc::new(parameter.clone()),
+                );
+            }
+        }
+    }
+
+    /// Resolves `pointer` against this index, loading and indexing the
+    /// referenced sibling file on demand if `pointer` carries a file path
+    /// component (anything before the `#`).
+    fn resolve(&mut self, pointer: &str, specification_file: &SpecificationFile) -> Option<ReferenceTarget> {
+        let file_path = pointer.split('#').next().unwrap_or("");
+
+        if !file_path.is_empty() && !self.loaded_files.contains_key(file_path) {
+            let sibling = specification_file.sibling(file_path);
+
+            if let Ok(contents) = sibling.contents() {
+                if let Ok(swagger) = serde_json::from_str::<Swagger>(&contents) {
+                    self.index_local(&swagger, file_path);
+                }
+            }
+
+            self.loaded_files.insert(file_path.to_string(), ());
+        }
+
+        if let Some(definition) = self.definitions.get(pointer) {
+            return Some(ReferenceTarget::Definition(Rc::clone(definition)));
+        }
+
+        if let Some(parameter) = self.parameters.get(pointer) {
+            return Some(ReferenceTarget::Parameter(Rc::clone(parameter)));
+        }
+
+        None
+    }
+}
+
+/// Walks every definition, shared parameter, and path operation in
+/// `swagger`, resolving each `$ref` pointer it contains against `index` and
+/// storing the resolved handle back onto the `Reference`/`DefinitionProperty`
+/// node it came from. Must run after parsing and before generation.
+pub fn resolve_references(specification_file: &SpecificationFile, swagger: &Swagger, index: &mut ReferenceIndex) {
+    let mut path_stack = Vec::new();
+    let mut visited = HashSet::new();
+
+    if let Some(definitions) = &swagger.definitions {
+        for name in definitions.keys() {
+            resolve_pointer(
+                &format!("#/definitions/{name}"),
+                specification_file,
+                swagger,
+                index,
+                &mut path_stack,
+                &mut visited,
+            );
+        }
+    }
+
+    if let Some(parameters) = &swagger.parameters {
+        for parameter in parameters.values() {
+            resolve_parameter_schema(parameter, specification_file, swagger, index, &mut path_stack, &mut visited);
+        }
+    }
+
+    if let Some(paths) = &swagger.paths {
+        for path_item in paths.values() {
+            for operation in path_item.operations.values() {
+                resolve_operation(operation, specification_file, swagger, index, &mut path_stack, &mut visited);
+            }
+        }
+    }
+}
+
+/// Resolves `pointer` against `index`, flagging `ResolvedReference::recursive`
+/// when `pointer` is already on `path_stack` — i.e. resolving it walked back
+/// through a pointer already being resolved higher up the same call chain,
+/// whether that's a direct self-reference (`A -> A`) or a longer cycle
+/// (`A -> B -> A`). The generator boxes a recursive target so the generated
+/// type stays finite-sized.
+///
+/// When `pointer` names a same-file definition that hasn't been visited yet,
+/// this recurses into that *same* `Definition` the rest of `resolve_references`
+/// walks (looked up from `swagger`, not a private copy owned by `index`), so
+/// the recursion is detected and recorded on the real `DefinitionProperty`
+/// nodes codegen later reads — not on a throwaway clone. `visited` ensures
+/// each definition's own references are only walked once: a definition
+/// reachable by more than one path (not a cycle, just shared) must not have
+/// its first, correctly-computed `recursive` flag overwritten by a later,
+/// unrelated visit.
+fn resolve_pointer(
+    pointer: &str,
+    specification_file: &SpecificationFile,
+    swagger: &Swagger,
+    index: &mut ReferenceIndex,
+    path_stack: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) -> Option<ResolvedReference> {
+    // OpenAPI 3.0 schemas/parameters are normalized into the same maps as
+    // Swagger 2.0 ones and indexed under the 2.0 pointer form.
+    let pointer = &pointer
+        .replacen("#/components/schemas/", "#/definitions/", 1)
+        .replacen("#/components/parameters/", "#/parameters/", 1);
+    let recursive = path_stack.iter().any(|seen| seen == pointer);
+    let target = index.resolve(pointer, specification_file);
+
+    if !recursive && visited.insert(pointer.to_string()) {
+        if let Some(name) = pointer.strip_prefix("#/definitions/") {
+            if let Some(definition) = swagger.definitions.as_ref().and_then(|definitions| definitions.get(name)) {
+                path_stack.push(pointer.to_string());
+                resolve_definition(definition, specification_file, swagger, index, path_stack, visited);
+                path_stack.pop();
+            }
+        }
+    }
+
+    // A same-file definition target is a copy of the `swagger` node taken
+    // after its own references were walked, not the index's copy (cloned by
+    // `ReferenceIndex::build` before anything was resolved), so a base seen
+    // through `allOf` carries its resolved `allOf` and property references.
+    let target = match pointer
+        .strip_prefix("#/definitions/")
+        .and_then(|name| swagger.definitions.as_ref()?.get(name))
+    {
+        Some(definition) => Some(ReferenceTarget::Definition(Rc::new(definition.clone()))),
+        None => target,
+    };
+
+    target.map(|target| ResolvedReference { target, recursive })
+}
+
+/// Resolves a `$ref` carried by an inline body parameter's own `schema`
+/// field (Swagger 2.0's `in: body` shape), e.g. a shared parameter declared
+/// under `parameters`/`components/parameters` whose payload is itself a
+/// `$ref` to a definition.
+fn resolve_parameter_schema(
+    parameter: &Parameter,
+    specification_file: &SpecificationFile,
+    swagger: &Swagger,
+    index: &mut ReferenceIndex,
+    path_stack: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) {
+    let Some(schema) = &parameter.schema else {
+        return;
+    };
+
+    if let Some(resolved) = resolve_pointer(&schema.path, specification_file, swagger, index, path_stack, visited) {
+        *schema.resolved.borrow_mut() = Some(Rc::new(resolved));
+    }
+}
+
+/// Resolves every `$ref` an operation carries: a bare `$ref` parameter (the
+/// norm for shared parameters like `api-version`), an inline body
+/// parameter's `schema`, each OpenAPI 3.0 `requestBody` content `schema`,
+/// and each response's `schema`.
+fn resolve_operation(
+    operation: &Operation,
+    specification_file: &SpecificationFile,
+    swagger: &Swagger,
+    index: &mut ReferenceIndex,
+    path_stack: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) {
+    for parameter in &operation.parameters {
+        match parameter {
+            ParameterOrReference::Reference(reference) => {
+                if let Some(resolved) =
+                    resolve_pointer(&reference.path, specification_file, swagger, index, path_stack, visited)
+                {
+                    *reference.resolved.borrow_mut() = Some(Rc::new(resolved));
+                }
+            }
+            ParameterOrReference::Parameter(parameter) => {
+                resolve_parameter_schema(parameter, specification_file, swagger, index, path_stack, visited);
+            }
+        }
+    }
+
+    if let Some(request_body) = &operation.request_body {
+        for media_type in request_body.content.values() {
+            let Some(schema) = &media_type.schema else {
+                continue;
+            };
+
+            if let Some(resolved) = resolve_pointer(&schema.path, specification_file, swagger, index, path_stack, visited)
+            {
+                *schema.resolved.borrow_mut() = Some(Rc::new(resolved));
+            }
+        }
+    }
+
+    for response in operation.responses.values() {
+        let Some(schema) = &response.schema else {
+            continue;
+        };
+
+        if let Some(resolved) = resolve_pointer(&schema.path, specification_file, swagger, index, path_stack, visited)
+        {
+            *schema.resolved.borrow_mut() = Some(Rc::new(resolved));
+        }
+    }
+}
+
+fn resolve_definition(
+    definition: &Definition,
+    specification_file: &SpecificationFile,
+    swagger: &Swagger,
+    index: &mut ReferenceIndex,
+    path_stack: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) {
+    if let Some(all_of) = &definition.all_of {
+        for reference in all_of {
+            if let Some(resolved) =
+                resolve_pointer(&reference.path, specification_file, swagger, index, path_stack, visited)
+            {
+                *reference.resolved.borrow_mut() = Some(Rc::new(resolved));
+            }
+        }
+    }
+
+    if let DefinitionType::Object { properties, .. } = &definition.schema {
+        for property in properties.values() {
+            resolve_property(property, specification_file, swagger, index, path_stack, visited);
+        }
+    }
+}
+
+/// Resolves a single property's own `$ref` (if any), then recurses into an
+/// array property's `items` — itself a `DefinitionProperty`, so a `$ref`
+/// array item (`Vec<Resource>`, the shape of most Azure list responses) is
+/// resolved the same way a direct `$ref` property is, including nested
+/// arrays-of-arrays.
+fn resolve_property(
+    property: &DefinitionProperty,
+    specification_file: &SpecificationFile,
+    swagger: &Swagger,
+    index: &mut ReferenceIndex,
+    path_stack: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) {
+    if let Some(path) = &property.reference {
+        if let Some(resolved) = resolve_pointer(path, specification_file, swagger, index, path_stack, visited) {
+            *property.resolved_reference.borrow_mut() = Some(Rc::new(resolved));
+        }
+    }
+
+    if let DefinitionType::Array { items, .. } = &property.schema {
+        resolve_property(items, specification_file, swagger, index, path_stack, visited);
+    }
+}
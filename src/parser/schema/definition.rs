@@ -1,9 +1,11 @@
-use crate::parser::schema::reference::Reference;
+use crate::parser::schema::reference::{Reference, ResolvedReference};
 use serde::Deserialize;
 use serde_json::Value;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DefinitionPropertyType {
     Object,
@@ -14,7 +16,7 @@ pub enum DefinitionPropertyType {
     Array,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum DefinitionType {
     Object {
@@ -24,7 +26,11 @@ pub enum DefinitionType {
         additional: HashMap<String, Value>,
     },
     Array {
-        items: Box<DefinitionType>,
+        // A plain `DefinitionType` can't carry a sibling `$ref`, so
+        // `DefinitionProperty` (which already solves "might be a $ref or an
+        // inline schema" for object properties) is reused here instead of a
+        // second, array-specific representation.
+        items: Box<DefinitionProperty>,
         #[serde(flatten)]
         additional: HashMap<String, Value>,
     },
@@ -46,7 +52,7 @@ pub enum DefinitionType {
     },
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct DefinitionProperty {
     #[serde(flatten)]
     pub schema: DefinitionType,
@@ -60,12 +66,37 @@ pub struct DefinitionProperty {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "$ref")]
     pub reference: Option<String>,
+    /// Filled in by `resolve_references` once `reference` has been resolved
+    /// against a `ReferenceIndex`; `None` until then, and always `None` when
+    /// `reference` is `None`.
+    #[serde(skip)]
+    pub resolved_reference: RefCell<Option<Rc<ResolvedReference>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "readOnly")]
     pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "x-ms-enum")]
+    pub x_ms_enum: Option<XMsEnum>,
+    /// OpenAPI `format` hint (`int64`, `date-time`, `uuid`, ...), used by the
+    /// generator's format registry to pick a precise Rust type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+/// Azure's extension to a schema `enum`: a name to generate the Rust enum
+/// under, and `modelAsString` to request an `Unknown(String)` catch-all
+/// variant so values the client doesn't recognize yet still round-trip.
+#[derive(Debug, Clone, Deserialize)]
+pub struct XMsEnum {
+    pub name: String,
+    #[serde(rename = "modelAsString")]
+    pub model_as_string: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Definition {
     #[serde(flatten)]
     pub schema: DefinitionType,
@@ -0,0 +1,11 @@
+pub mod definition;
+pub mod parameter;
+pub mod path;
+pub mod reference;
+pub mod swagger;
+
+pub use definition::{Definition, DefinitionProperty, DefinitionPropertyType, DefinitionType, XMsEnum};
+pub use parameter::{Parameter, PropertyType};
+pub use path::{MediaType, Operation, ParameterOrReference, PathItem, RequestBody, Response};
+pub use reference::{Reference, ReferenceTarget, ResolvedReference};
+pub use swagger::{Components, Server, Swagger};
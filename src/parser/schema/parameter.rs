@@ -0,0 +1,46 @@
+use crate::parser::schema::reference::Reference;
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Parameter {
+    pub name: Option<String>,
+    #[serde(rename = "in")]
+    pub location: Option<String>,
+    pub required: Option<bool>,
+    pub schema: Option<Reference>,
+    #[serde(rename = "type")]
+    pub property_type: Option<PropertyType>,
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PropertyType {
+    Object,
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Array,
+}
+
+impl<'de> Deserialize<'de> for PropertyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let property_type_str = String::deserialize(deserializer)?;
+
+        match property_type_str.to_lowercase().as_str() {
+            "object" => Ok(PropertyType::Object),
+            "string" => Ok(PropertyType::String),
+            "number" => Ok(PropertyType::Number),
+            "integer" => Ok(PropertyType::Integer),
+            "boolean" => Ok(PropertyType::Boolean),
+            "array" => Ok(PropertyType::Array),
+            _ => Err(serde::de::Error::unknown_variant(
+                &property_type_str,
+                &["object", "string", "number", "integer", "boolean", "array"],
+            )),
+        }
+    }
+}
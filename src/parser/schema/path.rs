@@ -0,0 +1,58 @@
+use crate::parser::models::{HttpStatus, Method};
+use crate::parser::schema::parameter::Parameter;
+use crate::parser::schema::reference::Reference;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// An inline parameter or a `$ref` to one declared under `parameters`
+/// (Swagger 2.0) / `components/parameters` (OpenAPI 3.0).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ParameterOrReference {
+    Reference(Reference),
+    Parameter(Parameter),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response {
+    pub description: Option<String>,
+    pub schema: Option<Reference>,
+}
+
+/// A single `content` entry under an OpenAPI 3.0 `requestBody`, e.g. the
+/// `application/json` entry. Its `schema` resolves through the same
+/// `Definition`/`DefinitionType` tree a Swagger 2.0 `in: body` parameter's
+/// `schema` does.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MediaType {
+    pub schema: Option<Reference>,
+}
+
+/// OpenAPI 3.0's replacement for Swagger 2.0's `in: body` parameter: the
+/// request payload is described by `content`, keyed by media type, rather
+/// than living in the `parameters` array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequestBody {
+    #[serde(default)]
+    pub content: HashMap<String, MediaType>,
+    pub required: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Operation {
+    #[serde(rename = "operationId")]
+    pub id: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<ParameterOrReference>,
+    #[serde(rename = "requestBody")]
+    pub request_body: Option<RequestBody>,
+    #[serde(default)]
+    pub responses: HashMap<HttpStatus, Response>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PathItem {
+    #[serde(flatten)]
+    pub operations: HashMap<Method, Operation>,
+}
@@ -0,0 +1,34 @@
+use crate::parser::schema::definition::Definition;
+use crate::parser::schema::parameter::Parameter;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A raw `$ref` pointer as it appears in a spec document, e.g.
+/// `#/definitions/Foo` (Swagger 2.0) or `#/components/schemas/Foo` (OpenAPI 3.0).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Reference {
+    #[serde(rename = "$ref")]
+    pub path: String,
+    /// Filled in by `resolve_references` once a `ReferenceIndex` has resolved
+    /// `path`; `None` for a pointer that hasn't been resolved yet.
+    #[serde(skip)]
+    pub resolved: RefCell<Option<Rc<ResolvedReference>>>,
+}
+
+/// What a resolved `$ref` pointer actually points at.
+#[derive(Debug, Clone)]
+pub enum ReferenceTarget {
+    Definition(Rc<Definition>),
+    Parameter(Rc<Parameter>),
+}
+
+/// The result of resolving a `$ref` pointer against a `ReferenceIndex`.
+#[derive(Debug, Clone)]
+pub struct ResolvedReference {
+    pub target: ReferenceTarget,
+    /// Set when resolving this pointer walked back through a pointer already
+    /// on the current resolution path (`A -> B -> A`). The generator emits
+    /// such targets as `Box<T>` so the generated type stays finite-sized.
+    pub recursive: bool,
+}
@@ -0,0 +1,136 @@
+use crate::parser::schema::definition::Definition;
+use crate::parser::schema::parameter::Parameter;
+use crate::parser::schema::path::PathItem;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A normalized `servers` entry. Swagger 2.0's `host`/`schemes`/`basePath` trio
+/// is folded into a single `Server` per scheme so generation never needs to
+/// special-case the spec version.
+#[derive(Debug, Deserialize)]
+pub struct Server {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// OpenAPI 3.0's `components` block, deserialized as-is before
+/// normalization folds `schemas`/`parameters` into `Swagger`'s own flat
+/// `definitions`/`parameters` maps.
+#[derive(Debug, Default, Deserialize)]
+pub struct Components {
+    #[serde(default)]
+    pub schemas: HashMap<String, Definition>,
+    #[serde(default)]
+    pub parameters: HashMap<String, Parameter>,
+}
+
+/// Raw Swagger 2.0 document shape, deserialized as-is before normalization.
+#[derive(Debug, Deserialize)]
+struct SwaggerV2Document {
+    host: Option<String>,
+    #[serde(rename = "basePath")]
+    base_path: Option<String>,
+    schemes: Option<Vec<String>>,
+    consumes: Option<Vec<String>>,
+    produces: Option<Vec<String>>,
+    paths: Option<HashMap<String, PathItem>>,
+    #[serde(default)]
+    definitions: HashMap<String, Definition>,
+    #[serde(default)]
+    parameters: HashMap<String, Parameter>,
+}
+
+/// Raw OpenAPI 3.0 document shape, deserialized as-is before normalization.
+#[derive(Debug, Deserialize)]
+struct OpenApiV3Document {
+    #[serde(default)]
+    servers: Vec<Server>,
+    paths: Option<HashMap<String, PathItem>>,
+    #[serde(default)]
+    components: Components,
+}
+
+/// Normalized, version-agnostic view of a spec document. `RustGenerator`
+/// consumes this shape regardless of whether the source document was
+/// Swagger 2.0 or OpenAPI 3.0.
+#[derive(Debug)]
+pub struct Swagger {
+    pub servers: Vec<Server>,
+    pub consumes: Option<Vec<String>>,
+    pub produces: Option<Vec<String>>,
+    pub paths: Option<HashMap<String, PathItem>>,
+    pub definitions: Option<HashMap<String, Definition>>,
+    pub parameters: Option<HashMap<String, Parameter>>,
+}
+
+impl From<SwaggerV2Document> for Swagger {
+    fn from(document: SwaggerV2Document) -> Self {
+        let schemes = document.schemes.unwrap_or_default();
+        let host = document.host;
+        let base_path = document.base_path.unwrap_or_default();
+
+        let servers = if let Some(host) = host {
+            if schemes.is_empty() {
+                vec![Server {
+                    url: format!("{host}{base_path}"),
+                    description: None,
+                }]
+            } else {
+                schemes
+                    .into_iter()
+                    .map(|scheme| Server {
+                        url: format!("{scheme}://{host}{base_path}"),
+                        description: None,
+                    })
+                    .collect()
+            }
+        } else {
+            Vec::new()
+        };
+
+        Swagger {
+            servers,
+            consumes: document.consumes,
+            produces: document.produces,
+            paths: document.paths,
+            definitions: Some(document.definitions),
+            parameters: Some(document.parameters),
+        }
+    }
+}
+
+impl From<OpenApiV3Document> for Swagger {
+    fn from(document: OpenApiV3Document) -> Self {
+        Swagger {
+            servers: document.servers,
+            consumes: None,
+            produces: None,
+            paths: document.paths,
+            definitions: Some(document.components.schemas),
+            parameters: Some(document.components.parameters),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Swagger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        if value.get("openapi").is_some() {
+            let document = OpenApiV3Document::deserialize(value).map_err(serde::de::Error::custom)?;
+            Ok(Swagger::from(document))
+        } else if value.get("swagger").is_some() {
+            let document = SwaggerV2Document::deserialize(value).map_err(serde::de::Error::custom)?;
+            Ok(Swagger::from(document))
+        } else {
+            Err(serde::de::Error::custom(
+                "specification document is missing both an `openapi` and a `swagger` discriminator field",
+            ))
+        }
+    }
+}